@@ -1,4 +1,7 @@
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use async_raft::async_trait::async_trait;
@@ -12,18 +15,229 @@ use async_raft::NodeId;
 use bincode::{deserialize, serialize};
 use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
+use futures::stream;
+use futures::stream::FuturesUnordered;
+use futures::Stream;
+use futures::StreamExt;
 use log::error;
 use tokio::sync::RwLock;
+use tokio::time::sleep;
 use tonic::transport::channel::Channel;
+use tonic::transport::{Certificate, ClientTlsConfig, Endpoint, Identity};
 
 use super::raft_service;
 use super::raft_service::raft_service_client::RaftServiceClient;
 use super::{ClientRequest, ClientResponse};
 
+/// Default per-RPC timeout applied to every Raft network call.
+const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(5);
+/// Default number of retries attempted after the initial call fails.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Base delay used to compute the exponential backoff between retries.
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+/// Largest number of bytes sent in a single snapshot chunk. `install_snapshot` is a
+/// client-streaming RPC (`stream InstallSnapshotChunk`), so the sender never builds one giant
+/// protobuf message for the whole snapshot and never holds more than one chunk's worth of wire
+/// bytes at a time. This bounds the *framing* overhead, not the `InstallSnapshotRequest` itself:
+/// `async-raft` hands it to `RaftNetwork::install_snapshot` already fully materialized, and the
+/// receiving side (`handle_install_snapshot`) still has to reassemble the chunks by offset
+/// before it can deserialize and apply the snapshot.
+const SNAPSHOT_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// The offset/length/`done` bounds of one chunk within a serialized snapshot. Kept separate
+/// from the chunk bytes so every retry attempt can reuse the same underlying buffer instead of
+/// cloning the whole chunk set per attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ChunkBounds {
+    offset: usize,
+    len: usize,
+    done: bool,
+}
+
+/// Computes the `ChunkBounds` for a buffer of `total` bytes, without touching the buffer itself.
+fn chunk_bounds(total: usize) -> Vec<ChunkBounds> {
+    if total == 0 {
+        return vec![ChunkBounds {
+            offset: 0,
+            len: 0,
+            done: true,
+        }];
+    }
+
+    (0..total)
+        .step_by(SNAPSHOT_CHUNK_SIZE)
+        .map(|offset| {
+            let len = SNAPSHOT_CHUNK_SIZE.min(total - offset);
+            ChunkBounds {
+                offset,
+                len,
+                done: offset + len == total,
+            }
+        })
+        .collect()
+}
+
+/// Builds a fresh, lazily-evaluated stream of `InstallSnapshotChunk`s over `data`: each item is
+/// only sliced and copied out of `data` as the stream is polled, so at most one chunk's worth of
+/// bytes is duplicated at a time rather than the whole snapshot up front.
+fn snapshot_chunk_stream(
+    data: Arc<Vec<u8>>,
+    bounds: Arc<Vec<ChunkBounds>>,
+) -> impl Stream<Item = raft_service::InstallSnapshotChunk> {
+    stream::iter(0..bounds.len()).map(move |i| {
+        let b = bounds[i];
+        raft_service::InstallSnapshotChunk {
+            offset: b.offset as u64,
+            data: data[b.offset..b.offset + b.len].to_vec(),
+            done: b.done,
+        }
+    })
+}
+
+/// Server-side counterpart to `snapshot_chunk_stream`: copies each `InstallSnapshotChunk` into a
+/// buffer at its reported `offset` (rather than appending) so a transport that redelivers or
+/// reorders chunks can't silently corrupt the reassembled payload. Kept separate from the
+/// stream-draining loop in `handle_install_snapshot` so the reassembly logic can be tested
+/// without a real `tonic::Streaming` source.
+fn reassemble_snapshot_chunks(chunks: &[raft_service::InstallSnapshotChunk]) -> Result<Vec<u8>> {
+    let total = chunks
+        .iter()
+        .map(|c| c.offset as usize + c.data.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut buf = vec![0u8; total];
+    for chunk in chunks {
+        let start = chunk.offset as usize;
+        let end = start + chunk.data.len();
+        buf[start..end].copy_from_slice(&chunk.data);
+    }
+
+    if !chunks.iter().any(|c| c.done) {
+        return Err(anyhow::Error::msg(
+            "install_snapshot stream ended before a chunk marked done",
+        ));
+    }
+
+    Ok(buf)
+}
+
+/// Receiving-side handler for the client-streaming `InstallSnapshot` RPC: drains the incoming
+/// `InstallSnapshotChunk` stream, reassembles it with `reassemble_snapshot_chunks`, deserializes
+/// the result back into an `InstallSnapshotRequest`, and hands it to `sink` (the concrete
+/// `RaftService` implementation wiring this into a running `Raft` instance lives outside this
+/// file, alongside the rest of the server).
+pub async fn handle_install_snapshot<S: SnapshotSink>(
+    sink: &S,
+    mut chunks: impl Stream<Item = std::result::Result<raft_service::InstallSnapshotChunk, tonic::Status>>
+        + Unpin,
+) -> Result<raft_service::InstallSnapshotResponse> {
+    let mut received = Vec::new();
+    while let Some(chunk) = chunks.next().await {
+        received.push(chunk?);
+    }
+
+    let data = reassemble_snapshot_chunks(&received)?;
+    let rpc: InstallSnapshotRequest = deserialize(&data)?;
+    let response = sink.install_snapshot(rpc).await?;
+    Ok(raft_service::InstallSnapshotResponse {
+        data: serialize(&response)?,
+    })
+}
+
+/// Applies a fully reassembled `InstallSnapshotRequest` to the underlying Raft node. Separated
+/// from `handle_install_snapshot` so the chunk-reassembly logic can be tested with a fake sink,
+/// independent of whatever concrete `Raft<...>` wiring the server uses.
+#[async_trait]
+pub trait SnapshotSink {
+    async fn install_snapshot(
+        &self,
+        rpc: InstallSnapshotRequest,
+    ) -> Result<InstallSnapshotResponse>;
+}
+
+/// TLS material used to secure inter-node Raft RPCs, so log entries and snapshots aren't sent
+/// in the clear when peers talk over an untrusted network.
+#[derive(Clone)]
+pub struct TlsConfig {
+    /// PEM-encoded client certificate, for mutual TLS.
+    pub cert: Vec<u8>,
+    /// PEM-encoded private key matching `cert`.
+    pub key: Vec<u8>,
+    /// PEM-encoded CA roots used to validate the peer's certificate.
+    pub ca_roots: Vec<u8>,
+    /// Expected server name (SNI / certificate CN) of the peer, since peers are dialed by
+    /// socket address rather than hostname.
+    pub domain_name: String,
+}
+
+impl std::fmt::Debug for TlsConfig {
+    /// Redacts `cert`/`key`/`ca_roots` so a stray `{:?}` while debugging a handshake failure
+    /// can't leak a private key into logs.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlsConfig")
+            .field("cert", &"<redacted>")
+            .field("key", &"<redacted>")
+            .field("ca_roots", &"<redacted>")
+            .field("domain_name", &self.domain_name)
+            .finish()
+    }
+}
+
+impl TlsConfig {
+    fn client_config(&self) -> ClientTlsConfig {
+        ClientTlsConfig::new()
+            .identity(Identity::from_pem(&self.cert, &self.key))
+            .ca_certificate(Certificate::from_pem(&self.ca_roots))
+            .domain_name(&self.domain_name)
+    }
+}
+
+/// Computes which currently-cached nodes must be torn down and which `(NodeId, SocketAddr)`
+/// pairs must be (re)dialed to reconcile `current` with the desired `members`. Kept separate
+/// from `RaftRouter::update_config` so the diffing logic can be tested without a live gRPC
+/// server to dial against.
+fn diff_membership(
+    current: &[(NodeId, SocketAddr)],
+    members: &BTreeMap<NodeId, SocketAddr>,
+) -> (Vec<NodeId>, Vec<(NodeId, SocketAddr)>) {
+    let to_remove = current
+        .iter()
+        .filter(|(id, _)| !members.contains_key(id))
+        .map(|(id, _)| *id)
+        .collect();
+
+    let to_dial = members
+        .iter()
+        .filter(|(id, addr)| {
+            current
+                .iter()
+                .find(|(cid, _)| cid == *id)
+                .map_or(true, |(_, current_addr)| current_addr != *addr)
+        })
+        .map(|(&id, &addr)| (id, addr))
+        .collect();
+
+    (to_remove, to_dial)
+}
+
+/// Dials `addr`, securing the channel with `tls` when one is configured.
+async fn dial(addr: SocketAddr, tls: Option<&TlsConfig>) -> Result<RaftServiceClient<Channel>> {
+    match tls {
+        Some(tls) => {
+            let endpoint = Endpoint::from_shared(format!("https://{}", addr))?
+                .tls_config(tls.client_config())?;
+            Ok(RaftServiceClient::new(endpoint.connect().await?))
+        }
+        None => Ok(RaftServiceClient::connect(format!("http://{}", addr)).await?),
+    }
+}
+
 #[allow(dead_code)]
 pub struct Client {
     rpc_client: RaftServiceClient<Channel>,
     addr: SocketAddr,
+    tls: Option<TlsConfig>,
 }
 
 impl Client {
@@ -37,24 +251,93 @@ impl Client {
         let response = self.rpc_client.forward(message).await?;
         Ok(deserialize(&response.get_ref().data)?)
     }
+
+    /// Re-dials the peer, replacing the cached channel. Called after a transport-level failure
+    /// so that a dead connection (peer restart, TCP reset) doesn't keep failing forever.
+    async fn reconnect(&mut self) -> Result<()> {
+        self.rpc_client = dial(self.addr, self.tls.as_ref()).await?;
+        Ok(())
+    }
+}
+
+/// Liveness snapshot for a single peer, updated after every RPC attempt made against it.
+#[derive(Debug, Clone, Default)]
+pub struct HealthStatus {
+    /// When the last successful RPC to this node completed, if any.
+    pub last_success: Option<Instant>,
+    /// Number of RPCs that have failed in a row since the last success.
+    pub consecutive_failures: u32,
+    /// Round-trip time of the last successful RPC.
+    pub last_rtt: Option<Duration>,
+}
+
+impl HealthStatus {
+    fn record_success(&mut self, rtt: Duration) {
+        self.last_success = Some(Instant::now());
+        self.last_rtt = Some(rtt);
+        self.consecutive_failures = 0;
+    }
+
+    fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+    }
 }
 
 pub struct RaftRouter {
     pub clients: DashMap<NodeId, RwLock<Client>>,
+    /// Per-node liveness, updated as RPCs against each node succeed or fail.
+    health: DashMap<NodeId, HealthStatus>,
+    /// Timeout applied to every individual RPC attempt.
+    rpc_timeout: Duration,
+    /// How many times a failed RPC is retried before giving up.
+    max_retries: u32,
+    /// Base delay for the exponential backoff applied between retries.
+    retry_base_delay: Duration,
+    /// TLS configuration used to dial peers, if inter-node RPCs should be encrypted.
+    tls: Option<TlsConfig>,
 }
 
 impl RaftRouter {
     pub fn new() -> Self {
-        let clients = DashMap::new();
-        Self { clients }
+        Self::with_timeout_policy(
+            DEFAULT_RPC_TIMEOUT,
+            DEFAULT_MAX_RETRIES,
+            DEFAULT_RETRY_BASE_DELAY,
+        )
+    }
+
+    /// Builds a `RaftRouter` with a custom timeout/retry policy, so deployments that see higher
+    /// network latency (or want to fail fast) can tune it instead of being stuck with the
+    /// defaults.
+    pub fn with_timeout_policy(
+        rpc_timeout: Duration,
+        max_retries: u32,
+        retry_base_delay: Duration,
+    ) -> Self {
+        Self {
+            clients: DashMap::new(),
+            health: DashMap::new(),
+            rpc_timeout,
+            max_retries,
+            retry_base_delay,
+            tls: None,
+        }
+    }
+
+    /// Enables TLS for every channel this router dials from now on, so operators running Raft
+    /// traffic across an untrusted network can require mutual TLS between peers.
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
     }
 
     pub async fn add_client(&self, id: NodeId, addr: SocketAddr) -> Result<()> {
         match self.clients.entry(id) {
             Entry::Vacant(entry) => {
                 let client = Client {
-                    rpc_client: RaftServiceClient::connect(format!("http://{}", addr)).await?,
+                    rpc_client: dial(addr, self.tls.as_ref()).await?,
                     addr,
+                    tls: self.tls.clone(),
                 };
                 entry.insert(RwLock::new(client));
             }
@@ -63,9 +346,209 @@ impl RaftRouter {
         Ok(())
     }
 
-    #[allow(dead_code)]
+    /// Tears down the cached channel for a node that has left the cluster, so the client map
+    /// doesn't grow monotonically as nodes come and go.
+    pub fn remove_client(&self, id: NodeId) {
+        self.clients.remove(&id);
+        self.health.remove(&id);
+    }
+
+    /// Reconciles the cached clients with the given membership: nodes no longer present are
+    /// torn down, new nodes are dialed, and nodes whose address changed are re-dialed. Lets
+    /// `async-raft` dynamic membership changes take effect without restarting the process.
+    ///
+    /// Every peer in `members` is attempted even if an earlier one fails to dial: a single
+    /// unreachable new peer shouldn't leave the rest of the reconciliation half-applied. If any
+    /// dial fails, the successfully-reconciled peers still take effect and the returned `Err`
+    /// lists which node IDs still need a redial.
+    pub async fn update_config(&self, members: BTreeMap<NodeId, SocketAddr>) -> Result<()> {
+        let mut current = Vec::with_capacity(self.clients.len());
+        for entry in self.clients.iter() {
+            current.push((*entry.key(), entry.value().read().await.addr));
+        }
+
+        let (to_remove, to_dial) = diff_membership(&current, &members);
+
+        for id in to_remove {
+            self.remove_client(id);
+        }
+
+        let mut failures = Vec::new();
+        for (id, addr) in to_dial {
+            self.remove_client(id);
+            if let Err(e) = self.add_client(id, addr).await {
+                failures.push(format!("node {}: {}", id, e));
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::Error::msg(format!(
+                "failed to redial {} peer(s) during membership reload: {}",
+                failures.len(),
+                failures.join("; ")
+            )))
+        }
+    }
+
+    /// Returns the node ID and a human-readable address/health summary for every known peer.
     pub async fn clients(&self) -> Vec<(NodeId, String)> {
-        todo!()
+        let mut clients = Vec::with_capacity(self.clients.len());
+        for entry in self.clients.iter() {
+            let id = *entry.key();
+            let addr = entry.value().read().await.addr;
+            let summary = match self.health.get(&id) {
+                Some(status) => match status.last_success {
+                    Some(last_success) => format!(
+                        "{} (last seen {:.1}s ago, rtt {:?}, {} consecutive failures)",
+                        addr,
+                        last_success.elapsed().as_secs_f32(),
+                        status.last_rtt.unwrap_or_default(),
+                        status.consecutive_failures
+                    ),
+                    None => format!(
+                        "{} (never reachable, {} consecutive failures)",
+                        addr, status.consecutive_failures
+                    ),
+                },
+                None => format!("{} (no RPCs attempted yet)", addr),
+            };
+            clients.push((id, summary));
+        }
+        clients
+    }
+
+    /// Returns the structured per-node health map, for admin/metrics endpoints that want to
+    /// report which peers are currently reachable.
+    pub fn health_snapshot(&self) -> BTreeMap<NodeId, HealthStatus> {
+        self.health
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect()
+    }
+
+    fn record_success(&self, target: NodeId, rtt: Duration) {
+        self.health
+            .entry(target)
+            .or_insert_with(HealthStatus::default)
+            .record_success(rtt);
+    }
+
+    fn record_failure(&self, target: NodeId) {
+        self.health
+            .entry(target)
+            .or_insert_with(HealthStatus::default)
+            .record_failure();
+    }
+
+    /// Exponential backoff delay before the `attempt`-th retry (0-indexed). Saturates instead
+    /// of overflowing when a deployment configures a very large `max_retries`.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        self.retry_base_delay.saturating_mul(factor)
+    }
+
+    /// Forwards `req` to every node in `targets` concurrently, returning one result per target
+    /// once all have completed. Used when a follower must probe or forward a write to multiple
+    /// candidate leaders: tail latency stays bounded by the slowest single node instead of the
+    /// sum of all of them.
+    pub async fn call_many<D: AppData>(
+        &self,
+        targets: &[NodeId],
+        req: ClientWriteRequest<D>,
+    ) -> Vec<(NodeId, Result<ClientResponse>)> {
+        let message = match serialize(&req) {
+            Ok(data) => raft_service::ClientWriteRequest { data },
+            Err(e) => {
+                let err = format!("failed to serialize request: {}", e);
+                return targets
+                    .iter()
+                    .map(|&target| (target, Err(anyhow::Error::msg(err.clone()))))
+                    .collect();
+            }
+        };
+
+        let mut calls = FuturesUnordered::new();
+        for &target in targets {
+            let message = message.clone();
+            calls.push(async move { (target, self.forward_with_retry(target, message).await) });
+        }
+
+        let mut results = Vec::with_capacity(targets.len());
+        while let Some(result) = calls.next().await {
+            results.push(result);
+        }
+        results
+    }
+
+    /// Forwards an already-serialized `ClientWriteRequest` to `target`, applying the same
+    /// timeout/retry/backoff policy and health tracking as the `RaftNetwork` RPCs, so a single
+    /// unreachable peer in a `call_many` fan-out can't hang the call or go unnoticed by health
+    /// reporting.
+    async fn forward_with_retry(
+        &self,
+        target: NodeId,
+        message: raft_service::ClientWriteRequest,
+    ) -> Result<ClientResponse> {
+        let mut attempt = 0;
+        loop {
+            let started_at = Instant::now();
+            let result = {
+                let client = self
+                    .clients
+                    .get(&target)
+                    .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
+                let mut guard = client.write().await;
+                tokio::time::timeout(self.rpc_timeout, guard.rpc_client.forward(message.clone()))
+                    .await
+            };
+
+            match result {
+                Ok(Ok(response)) => {
+                    self.record_success(target, started_at.elapsed());
+                    return Ok(deserialize(&response.get_ref().data)?);
+                }
+                Ok(Err(status)) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(status.to_string()));
+                    }
+                    error!(
+                        "forward to {} failed (attempt {}/{}): {}",
+                        target,
+                        attempt + 1,
+                        self.max_retries,
+                        status
+                    );
+                }
+                Err(_elapsed) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(format!(
+                            "forward to {} timed out after {} attempts",
+                            target,
+                            attempt + 1
+                        )));
+                    }
+                    error!(
+                        "forward to {} timed out (attempt {}/{})",
+                        target,
+                        attempt + 1,
+                        self.max_retries
+                    );
+                }
+            }
+
+            if let Some(client) = self.clients.get(&target) {
+                if let Err(e) = client.write().await.reconnect().await {
+                    error!("failed to reconnect to {}: {}", target, e);
+                }
+            }
+
+            sleep(self.backoff_delay(attempt)).await;
+            attempt += 1;
+        }
     }
 }
 
@@ -76,22 +559,71 @@ impl RaftNetwork<ClientRequest> for RaftRouter {
         target: NodeId,
         rpc: AppendEntriesRequest<ClientRequest>,
     ) -> Result<AppendEntriesResponse> {
-        let client = self
-            .clients
-            .get(&target)
-            .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
-
         let payload = raft_service::AppendEntriesRequest {
             data: serialize(&rpc)?,
         };
-        let mut client = client.write().await;
 
-        match client.rpc_client.append_entries(payload).await {
-            Ok(response) => {
-                let response = deserialize(&response.into_inner().data)?;
-                Ok(response)
+        let mut attempt = 0;
+        loop {
+            let started_at = Instant::now();
+            let result = {
+                let client = self
+                    .clients
+                    .get(&target)
+                    .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
+                let mut guard = client.write().await;
+                tokio::time::timeout(
+                    self.rpc_timeout,
+                    guard.rpc_client.append_entries(payload.clone()),
+                )
+                .await
+            };
+
+            match result {
+                Ok(Ok(response)) => {
+                    self.record_success(target, started_at.elapsed());
+                    let response = deserialize(&response.into_inner().data)?;
+                    return Ok(response);
+                }
+                Ok(Err(status)) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(status.to_string()));
+                    }
+                    error!(
+                        "append_entries to {} failed (attempt {}/{}): {}",
+                        target,
+                        attempt + 1,
+                        self.max_retries,
+                        status
+                    );
+                }
+                Err(_elapsed) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(format!(
+                            "append_entries to {} timed out after {} attempts",
+                            target,
+                            attempt + 1
+                        )));
+                    }
+                    error!(
+                        "append_entries to {} timed out (attempt {}/{})",
+                        target,
+                        attempt + 1,
+                        self.max_retries
+                    );
+                }
             }
-            Err(status) => Err(anyhow::Error::msg(status.to_string())),
+
+            if let Some(client) = self.clients.get(&target) {
+                if let Err(e) = client.write().await.reconnect().await {
+                    error!("failed to reconnect to {}: {}", target, e);
+                }
+            }
+
+            sleep(self.backoff_delay(attempt)).await;
+            attempt += 1;
         }
     }
 
@@ -100,45 +632,335 @@ impl RaftNetwork<ClientRequest> for RaftRouter {
         target: NodeId,
         rpc: InstallSnapshotRequest,
     ) -> Result<InstallSnapshotResponse> {
-        let client = self
-            .clients
-            .get(&target)
-            .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
+        let data = Arc::new(serialize(&rpc)?);
+        let bounds = Arc::new(chunk_bounds(data.len()));
 
-        let payload = raft_service::InstallSnapshotRequest {
-            data: serialize(&rpc)?,
-        };
-        let mut client = client.write().await;
+        let mut attempt = 0;
+        loop {
+            let started_at = Instant::now();
+            let result = {
+                let client = self
+                    .clients
+                    .get(&target)
+                    .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
+                let mut guard = client.write().await;
+                tokio::time::timeout(
+                    self.rpc_timeout,
+                    guard
+                        .rpc_client
+                        .install_snapshot(snapshot_chunk_stream(data.clone(), bounds.clone())),
+                )
+                .await
+            };
 
-        match client.rpc_client.install_snapshot(payload).await {
-            Ok(response) => {
-                let response = deserialize(&response.into_inner().data)?;
-                Ok(response)
+            match result {
+                Ok(Ok(response)) => {
+                    self.record_success(target, started_at.elapsed());
+                    let response = deserialize(&response.into_inner().data)?;
+                    return Ok(response);
+                }
+                Ok(Err(status)) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(status.to_string()));
+                    }
+                    error!(
+                        "install_snapshot to {} failed (attempt {}/{}): {}",
+                        target,
+                        attempt + 1,
+                        self.max_retries,
+                        status
+                    );
+                }
+                Err(_elapsed) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(format!(
+                            "install_snapshot to {} timed out after {} attempts",
+                            target,
+                            attempt + 1
+                        )));
+                    }
+                    error!(
+                        "install_snapshot to {} timed out (attempt {}/{})",
+                        target,
+                        attempt + 1,
+                        self.max_retries
+                    );
+                }
             }
-            Err(status) => Err(anyhow::Error::msg(status.to_string())),
+
+            if let Some(client) = self.clients.get(&target) {
+                if let Err(e) = client.write().await.reconnect().await {
+                    error!("failed to reconnect to {}: {}", target, e);
+                }
+            }
+
+            sleep(self.backoff_delay(attempt)).await;
+            attempt += 1;
         }
     }
 
     async fn vote(&self, target: NodeId, rpc: VoteRequest) -> Result<VoteResponse> {
-        let client = self
-            .clients
-            .get(&target)
-            .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
-
         let payload = raft_service::VoteRequest {
             data: serialize(&rpc)?,
         };
-        let mut client = client.write().await;
 
-        match client.rpc_client.vote(payload).await {
-            Ok(response) => {
-                let response = deserialize(&response.into_inner().data)?;
-                Ok(response)
+        let mut attempt = 0;
+        loop {
+            let started_at = Instant::now();
+            let result = {
+                let client = self
+                    .clients
+                    .get(&target)
+                    .ok_or_else(|| anyhow::Error::msg(format!("Client {} not found.", target)))?;
+                let mut guard = client.write().await;
+                tokio::time::timeout(self.rpc_timeout, guard.rpc_client.vote(payload.clone())).await
+            };
+
+            match result {
+                Ok(Ok(response)) => {
+                    self.record_success(target, started_at.elapsed());
+                    let response = deserialize(&response.into_inner().data)?;
+                    return Ok(response);
+                }
+                Ok(Err(status)) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        error!("error connecting to peer: {}", status.to_string());
+                        return Err(anyhow::Error::msg(status.to_string()));
+                    }
+                    error!(
+                        "vote to {} failed (attempt {}/{}): {}",
+                        target,
+                        attempt + 1,
+                        self.max_retries,
+                        status
+                    );
+                }
+                Err(_elapsed) => {
+                    self.record_failure(target);
+                    if attempt >= self.max_retries {
+                        return Err(anyhow::Error::msg(format!(
+                            "vote to {} timed out after {} attempts",
+                            target,
+                            attempt + 1
+                        )));
+                    }
+                    error!(
+                        "vote to {} timed out (attempt {}/{})",
+                        target,
+                        attempt + 1,
+                        self.max_retries
+                    );
+                }
             }
-            Err(status) => {
-                error!("error connecting to peer: {}", status.to_string());
-                Err(anyhow::Error::msg(status.to_string()))
+
+            if let Some(client) = self.clients.get(&target) {
+                if let Err(e) = client.write().await.reconnect().await {
+                    error!("failed to reconnect to {}: {}", target, e);
+                }
             }
+
+            sleep(self.backoff_delay(attempt)).await;
+            attempt += 1;
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_grows_exponentially() {
+        let router = RaftRouter::with_timeout_policy(
+            Duration::from_secs(1),
+            DEFAULT_MAX_RETRIES,
+            Duration::from_millis(100),
+        );
+        assert_eq!(router.backoff_delay(0), Duration::from_millis(100));
+        assert_eq!(router.backoff_delay(1), Duration::from_millis(200));
+        assert_eq!(router.backoff_delay(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn health_status_resets_consecutive_failures_on_success() {
+        let mut health = HealthStatus::default();
+        health.record_failure();
+        health.record_failure();
+        health.record_failure();
+        assert_eq!(health.consecutive_failures, 3);
+        assert!(health.last_success.is_none());
+
+        let rtt = Duration::from_millis(42);
+        health.record_success(rtt);
+        assert_eq!(health.consecutive_failures, 0);
+        assert_eq!(health.last_rtt, Some(rtt));
+        assert!(health.last_success.is_some());
+    }
+
+    #[test]
+    fn chunk_bounds_empty_input_yields_one_done_chunk() {
+        let bounds = chunk_bounds(0);
+        assert_eq!(
+            bounds,
+            vec![ChunkBounds {
+                offset: 0,
+                len: 0,
+                done: true
+            }]
+        );
+    }
+
+    #[test]
+    fn chunk_bounds_splits_on_size_boundary() {
+        let bounds = chunk_bounds(SNAPSHOT_CHUNK_SIZE + 1);
+        assert_eq!(
+            bounds,
+            vec![
+                ChunkBounds {
+                    offset: 0,
+                    len: SNAPSHOT_CHUNK_SIZE,
+                    done: false
+                },
+                ChunkBounds {
+                    offset: SNAPSHOT_CHUNK_SIZE,
+                    len: 1,
+                    done: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn chunk_bounds_exact_multiple_of_chunk_size() {
+        let bounds = chunk_bounds(SNAPSHOT_CHUNK_SIZE * 2);
+        assert_eq!(
+            bounds,
+            vec![
+                ChunkBounds {
+                    offset: 0,
+                    len: SNAPSHOT_CHUNK_SIZE,
+                    done: false
+                },
+                ChunkBounds {
+                    offset: SNAPSHOT_CHUNK_SIZE,
+                    len: SNAPSHOT_CHUNK_SIZE,
+                    done: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn snapshot_chunk_stream_reassembles_to_original_bytes() {
+        let data: Vec<u8> = (0..(SNAPSHOT_CHUNK_SIZE + 100))
+            .map(|i| (i % 256) as u8)
+            .collect();
+        let bounds = Arc::new(chunk_bounds(data.len()));
+        let data = Arc::new(data);
+
+        let chunks: Vec<_> =
+            futures::executor::block_on(snapshot_chunk_stream(data.clone(), bounds).collect());
+        assert_eq!(chunks.len(), 2);
+        assert!(!chunks[0].done);
+        assert!(chunks[1].done);
+
+        let mut reassembled = Vec::new();
+        for chunk in &chunks {
+            reassembled.extend_from_slice(&chunk.data);
+        }
+        assert_eq!(reassembled, *data);
+    }
+
+    #[test]
+    fn reassemble_snapshot_chunks_round_trips_chunked_data() {
+        let data: Vec<u8> = (0..(SNAPSHOT_CHUNK_SIZE + 100))
+            .map(|i| (i % 256) as u8)
+            .collect();
+        let bounds = Arc::new(chunk_bounds(data.len()));
+        let data = Arc::new(data);
+
+        let chunks: Vec<_> =
+            futures::executor::block_on(snapshot_chunk_stream(data.clone(), bounds).collect());
+        let reassembled = reassemble_snapshot_chunks(&chunks).unwrap();
+        assert_eq!(reassembled, *data);
+    }
+
+    #[test]
+    fn reassemble_snapshot_chunks_rejects_stream_missing_done_chunk() {
+        let chunks = vec![raft_service::InstallSnapshotChunk {
+            offset: 0,
+            data: vec![1, 2, 3],
+            done: false,
+        }];
+        assert!(reassemble_snapshot_chunks(&chunks).is_err());
+    }
+
+    #[test]
+    fn diff_membership_removes_dropped_nodes() {
+        let current = vec![
+            (1, "127.0.0.1:1001".parse().unwrap()),
+            (2, "127.0.0.1:1002".parse().unwrap()),
+        ];
+        let members = BTreeMap::from([(1, "127.0.0.1:1001".parse().unwrap())]);
+
+        let (to_remove, to_dial) = diff_membership(&current, &members);
+        assert_eq!(to_remove, vec![2]);
+        assert!(to_dial.is_empty());
+    }
+
+    #[test]
+    fn diff_membership_dials_new_nodes() {
+        let current = vec![(1, "127.0.0.1:1001".parse().unwrap())];
+        let members = BTreeMap::from([
+            (1, "127.0.0.1:1001".parse().unwrap()),
+            (2, "127.0.0.1:1002".parse().unwrap()),
+        ]);
+
+        let (to_remove, to_dial) = diff_membership(&current, &members);
+        assert!(to_remove.is_empty());
+        assert_eq!(to_dial, vec![(2, "127.0.0.1:1002".parse().unwrap())]);
+    }
+
+    #[test]
+    fn diff_membership_redials_changed_address() {
+        let current = vec![(1, "127.0.0.1:1001".parse().unwrap())];
+        let members = BTreeMap::from([(1, "127.0.0.1:9999".parse().unwrap())]);
+
+        let (to_remove, to_dial) = diff_membership(&current, &members);
+        assert!(to_remove.is_empty());
+        assert_eq!(to_dial, vec![(1, "127.0.0.1:9999".parse().unwrap())]);
+    }
+
+    #[test]
+    fn tls_config_debug_redacts_secrets() {
+        let tls = TlsConfig {
+            cert: b"cert-bytes".to_vec(),
+            key: b"super-secret-key".to_vec(),
+            ca_roots: b"ca-bytes".to_vec(),
+            domain_name: "raft.internal".to_string(),
+        };
+        let debug = format!("{:?}", tls);
+        assert!(!debug.contains("super-secret-key"));
+        assert!(!debug.contains("cert-bytes"));
+        assert!(!debug.contains("ca-bytes"));
+        assert!(debug.contains("raft.internal"));
+    }
+
+    #[test]
+    fn backoff_delay_saturates_instead_of_overflowing() {
+        let router = RaftRouter::with_timeout_policy(
+            Duration::from_secs(1),
+            1_000,
+            Duration::from_millis(100),
+        );
+        // `attempt` reaching 32 would overflow `2u32.pow(attempt)`; it must saturate instead of
+        // panicking (debug builds) or wrapping to a bogus short delay (release builds).
+        // The factor is capped at `u32::MAX`, so the delay is `base * u32::MAX`.
+        let capped = Duration::from_millis(100) * u32::MAX;
+        assert_eq!(router.backoff_delay(32), capped);
+        assert_eq!(router.backoff_delay(u32::MAX), capped);
+    }
+}